@@ -0,0 +1,44 @@
+// Prints each item without taking ownership of the slice.
+pub fn print_all(items: &[i32]) {
+    for item in items {
+        println!("{item}");
+    }
+}
+
+// Joins the items into a single comma-separated string, borrowing rather
+// than consuming them.
+pub fn join_display<T: ToString>(items: &[T]) -> String {
+    items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn print_all_leaves_the_vector_usable() {
+        let items = vec![1, 3, 5, 7, 78, 54];
+        print_all(&items);
+        assert_eq!(items, vec![1, 3, 5, 7, 78, 54]);
+    }
+
+    #[test]
+    fn join_display_leaves_the_vector_usable() {
+        let items = vec![1, 3, 5, 7, 78, 54];
+        let joined = join_display(&items);
+        assert_eq!(joined, "1, 3, 5, 7, 78, 54");
+        assert_eq!(items, vec![1, 3, 5, 7, 78, 54]);
+    }
+
+    #[test]
+    fn join_display_borrows_a_vec_of_strings() {
+        let items = vec![String::from("a"), String::from("b"), String::from("c")];
+        let joined = join_display(&items);
+        assert_eq!(joined, "a, b, c");
+        assert_eq!(items, vec!["a", "b", "c"]);
+    }
+}
@@ -0,0 +1,54 @@
+use std::fmt;
+use std::io::{self, stdin, Write};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    Eof,
+    Parse(String),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io(e) => write!(f, "couldn't read input: {e}"),
+            InputError::Eof => write!(f, "input closed before a value was entered"),
+            InputError::Parse(raw) => write!(f, "'{raw}' isn't a valid value"),
+        }
+    }
+}
+
+// Prints `prompt`, reads one line from stdin and parses it as `T`.
+pub fn prompt_parse<T: FromStr>(prompt: &str) -> Result<T, InputError> {
+    print!("{prompt}");
+    io::stdout().flush().map_err(InputError::Io)?;
+
+    let mut raw = String::new();
+    let bytes_read = stdin().read_line(&mut raw).map_err(InputError::Io)?;
+    if bytes_read == 0 {
+        return Err(InputError::Eof);
+    }
+
+    raw.trim()
+        .parse()
+        .map_err(|_| InputError::Parse(raw.trim().to_string()))
+}
+
+// Reprompts with `prompt` until a valid `T` is entered, up to `max_attempts`
+// tries, bailing out immediately on EOF or IO errors.
+pub fn read_until_valid<T: FromStr>(prompt: &str, max_attempts: u32) -> Result<T, InputError> {
+    let mut last_err = InputError::Eof;
+    for _ in 0..max_attempts {
+        match prompt_parse(prompt) {
+            Ok(value) => return Ok(value),
+            Err(InputError::Parse(raw)) => {
+                let e = InputError::Parse(raw);
+                println!("{e}");
+                last_err = e;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    Err(last_err)
+}
@@ -1,4 +1,7 @@
-use std::io::{stdin};
+mod display;
+mod input;
+mod patterns;
+mod reduction;
 
 fn main() {
     for num in 1..=10 {
@@ -40,17 +43,23 @@ fn main() {
     println!("_________________________________");
     //
     let vector = vec![1, 3, 5, 7, 78, 54];
-    for vec in vector {
-        println!("{vec}");
-    }
+    display::print_all(&vector);
+    println!("{}", display::join_display(&vector));
+    println!("still have {} items: {:?}", vector.len(), vector);
+
+    let names = vec![String::from("Ibrahim"), String::from("Ali"), String::from("Sara")];
+    println!("{}", display::join_display(&names));
+    println!("still have {} names: {:?}", names.len(), names);
 
     //
     println!("_________________________________");
-    let mut input = String::new();
-    println!("Enter a num");
-
-    stdin().read_line(&mut input).expect("failed to read line");
-    let num: i32 = input.trim().parse().expect("enter valid num");
+    let num: i32 = match input::read_until_valid("Enter a num: ", 5) {
+        Ok(value) => value,
+        Err(e) => {
+            println!("{e}");
+            return;
+        }
+    };
 
     for i in 1..=10 {
         println!("{} x {} = {}", num, i, num * i);
@@ -58,13 +67,7 @@ fn main() {
 
     //
     println!("_________________________________");
-    let height = 5;
-    for i in 1..=height {
-        for _ in 0..i {
-            print!("*");
-        }
-        println!()
-    }
+    print!("{}", patterns::triangle(5, '*', patterns::Align::Left));
 
     //
     println!("_________________________________");
@@ -77,10 +80,6 @@ fn main() {
     println!("_________________________________");
     println!("");
 
-    let calc = calculate(1, 5);
+    let calc = reduction::sum_even(1..=5);
     println!("{calc}");
 }
-
-fn calculate(botton: i32, top: i32) -> i32 {
-    (botton..=top).filter(|e| e % 2 == 0).sum()
-}
@@ -0,0 +1,90 @@
+pub enum Align {
+    Left,
+    Right,
+    Centered,
+}
+
+// Builds a triangle of `height` rows out of `fill`, aligned per `align`.
+pub fn triangle(height: usize, fill: char, align: Align) -> String {
+    let mut out = String::new();
+    for i in 1..=height {
+        let pad = match align {
+            Align::Left => 0,
+            Align::Right => height - i,
+            Align::Centered => (height - i) / 2,
+        };
+        out.push_str(&" ".repeat(pad));
+        out.push_str(&fill.to_string().repeat(i));
+        out.push('\n');
+    }
+    out
+}
+
+// Builds a `width` x `height` block of `fill`.
+pub fn rectangle(width: usize, height: usize, fill: char) -> String {
+    let mut out = String::new();
+    for _ in 0..height {
+        out.push_str(&fill.to_string().repeat(width));
+        out.push('\n');
+    }
+    out
+}
+
+// Builds a centered pyramid of `height` rows out of `fill`.
+pub fn pyramid(height: usize, fill: char) -> String {
+    let mut out = String::new();
+    for i in 1..=height {
+        let pad = height - i;
+        out.push_str(&" ".repeat(pad));
+        out.push_str(&fill.to_string().repeat(2 * i - 1));
+        out.push('\n');
+    }
+    out
+}
+
+// Builds a diamond (pyramid stacked on its mirror) of `height` rows per half.
+pub fn diamond(height: usize, fill: char) -> String {
+    let mut out = pyramid(height, fill);
+    for i in (1..height).rev() {
+        let pad = height - i;
+        out.push_str(&" ".repeat(pad));
+        out.push_str(&fill.to_string().repeat(2 * i - 1));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triangle_left_aligned() {
+        assert_eq!(triangle(3, '*', Align::Left), "*\n**\n***\n");
+    }
+
+    #[test]
+    fn triangle_right_aligned() {
+        assert_eq!(triangle(3, '*', Align::Right), "  *\n **\n***\n");
+    }
+
+    #[test]
+    fn triangle_centered() {
+        assert_eq!(triangle(3, '*', Align::Centered), " *\n**\n***\n");
+    }
+
+    #[test]
+    fn rectangle_block() {
+        assert_eq!(rectangle(3, 2, '#'), "###\n###\n");
+    }
+
+    #[test]
+    fn pyramid_shape() {
+        assert_eq!(pyramid(3, '*'), "  *\n ***\n*****\n");
+    }
+
+    #[test]
+    fn diamond_shape() {
+        assert_eq!(diamond(3, '*'), "  *\n ***\n*****\n ***\n  *\n");
+    }
+}
@@ -0,0 +1,71 @@
+use std::ops::{Add, Mul, RangeInclusive};
+
+// Filters `range` through `keep` and folds the survivors together with
+// `fold`, starting from `init` — built on the standard iterator chain so
+// exhaustion (and overflow at the type's max value) is handled the same
+// way the library's `RangeInclusive` iterator already handles it.
+pub fn reduce_range<T, P, F>(range: RangeInclusive<T>, keep: P, init: T, fold: F) -> T
+where
+    RangeInclusive<T>: Iterator<Item = T>,
+    P: Fn(&T) -> bool,
+    F: Fn(T, T) -> T,
+{
+    range.filter(keep).fold(init, fold)
+}
+
+pub fn sum_even<T>(range: RangeInclusive<T>) -> T
+where
+    RangeInclusive<T>: Iterator<Item = T>,
+    T: Copy + Add<Output = T> + std::ops::Rem<Output = T> + PartialEq + From<u8>,
+{
+    let zero = T::from(0);
+    let two = T::from(2);
+    reduce_range(range, |n| *n % two == zero, zero, |acc, n| acc + n)
+}
+
+pub fn sum_odd<T>(range: RangeInclusive<T>) -> T
+where
+    RangeInclusive<T>: Iterator<Item = T>,
+    T: Copy + Add<Output = T> + std::ops::Rem<Output = T> + PartialEq + From<u8>,
+{
+    let zero = T::from(0);
+    let two = T::from(2);
+    reduce_range(range, |n| *n % two != zero, zero, |acc, n| acc + n)
+}
+
+pub fn product<T>(range: RangeInclusive<T>) -> T
+where
+    RangeInclusive<T>: Iterator<Item = T>,
+    T: Copy + Mul<Output = T> + From<u8>,
+{
+    reduce_range(range, |_| true, T::from(1), |acc, n| acc * n)
+}
+
+pub fn count_matching<T, P>(range: RangeInclusive<T>, keep: P) -> T
+where
+    RangeInclusive<T>: Iterator<Item = T>,
+    T: Copy + Add<Output = T> + From<u8>,
+    P: Fn(&T) -> bool,
+{
+    reduce_range(range, keep, T::from(0), |acc, _| acc + T::from(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_odd_adds_only_odd_numbers() {
+        assert_eq!(sum_odd(1..=10), 25);
+    }
+
+    #[test]
+    fn product_multiplies_the_whole_range() {
+        assert_eq!(product(1..=5), 120);
+    }
+
+    #[test]
+    fn count_matching_counts_multiples_of_three() {
+        assert_eq!(count_matching(1..=10, |n| n % 3 == 0), 3);
+    }
+}